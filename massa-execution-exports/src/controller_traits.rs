@@ -3,7 +3,10 @@
 //! This module exports generic traits representing interfaces for interacting with the Execution worker
 
 use crate::types::ExecutionOutput;
+use crate::types::ExecutionTrace;
+use crate::types::FeeHistory;
 use crate::types::ReadOnlyExecutionRequest;
+use crate::types::TraceFilter;
 use crate::ExecutionError;
 use massa_models::api::EventFilter;
 use massa_models::output_event::SCOutputEvent;
@@ -84,8 +87,15 @@ pub trait ExecutionController: Send + Sync {
 
     /// Execute read-only SC function call without causing modifications to the consensus state
     ///
+    /// This is a thin wrapper around [`ExecutionController::execute_readonly_requests`]
+    /// that runs a single request through the same speculative-context setup.
+    ///
     /// # arguments
-    /// * `req`: an instance of `ReadOnlyCallRequest` describing the parameters of the execution
+    /// * `req`: an instance of `ReadOnlyExecutionRequest` describing the parameters of the execution.
+    ///   Its `state_overrides` are layered on top of the speculative snapshot before execution.
+    ///   Its `state_target`, when set, pins execution to a finalized slot or block instead of
+    ///   the moving active tip, returning `ExecutionError::StateTargetTooOld` if that point has
+    ///   fallen out of the retained history window.
     ///
     /// # returns
     /// An instance of `ExecutionOutput` containing a summary of the effects of the execution,
@@ -93,11 +103,62 @@ pub trait ExecutionController: Send + Sync {
     fn execute_readonly_request(
         &self,
         req: ReadOnlyExecutionRequest,
-    ) -> Result<ExecutionOutput, ExecutionError>;
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        self.execute_readonly_requests(vec![req])
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| {
+                Err(ExecutionError::RuntimeError(
+                    "execute_readonly_requests did not return a result for its single request"
+                        .to_string(),
+                ))
+            })
+    }
+
+    /// Execute a batch of read-only SC function calls without causing modifications to the
+    /// consensus state.
+    ///
+    /// All requests are executed sequentially on a single cloned speculative ledger: state
+    /// mutations (balance changes, datastore writes) produced by one request are visible to the
+    /// requests that follow it, but the whole scratch state is discarded once the batch
+    /// completes. This allows a caller to simulate a sequence such as "approve, then transfer,
+    /// then read balance" atomically without ever committing anything, similarly to
+    /// Ethereum's `eth_callMany` bundle simulation.
+    ///
+    /// Identical requests may be served from a memoized cache keyed by the current state
+    /// revision and the set of ledger entries the call actually reads, unless a request's
+    /// `bypass_cache` is set.
+    ///
+    /// # arguments
+    /// * `reqs`: the ordered list of requests to execute against the shared speculative state
+    ///
+    /// # returns
+    /// One `Result` per request, in the same order, each independently reporting success
+    /// (with the resulting `ExecutionOutput`) or failure.
+    fn execute_readonly_requests(
+        &self,
+        reqs: Vec<ReadOnlyExecutionRequest>,
+    ) -> Vec<Result<ExecutionOutput, ExecutionError>>;
 
     /// List which operations inside the provided list were not executed
     fn unexecuted_ops_among(&self, ops: &Set<OperationId>) -> Set<OperationId>;
 
+    /// Get call traces recorded for read-only and executed operations, optionally filtered by:
+    /// * start slot
+    /// * end slot
+    /// * top-level caller address
+    /// * top-level callee address
+    fn get_filtered_trace(&self, filter: TraceFilter) -> Vec<ExecutionTrace>;
+
+    /// Get fee statistics over a range of executed slots, for the requested percentiles.
+    ///
+    /// For each slot in `[start, end]` that was actually executed, returns the total
+    /// operation count together with the min/max/average fee and the requested fee
+    /// percentiles computed from the fees of the operations included in that slot's block.
+    /// Served from a ring buffer of recent slots, so only slots still within the retained
+    /// history window are returned.
+    fn get_fee_history(&self, start: Slot, end: Slot, percentiles: Vec<f64>) -> FeeHistory;
+
     /// Returns a boxed clone of self.
     /// Useful to allow cloning `Box<dyn ExecutionController>`.
     fn clone_box(&self) -> Box<dyn ExecutionController>;
@@ -0,0 +1,26 @@
+// Copyright (c) 2022 MASSA LABS <info@massa.net>
+
+//! Definition of the errors that can occur during execution
+
+use thiserror::Error;
+
+/// Errors of the execution component
+#[non_exhaustive]
+#[derive(Error, Debug, Clone)]
+pub enum ExecutionError {
+    /// Error reported by the VM
+    #[error("VM error: {0}")]
+    VMError(String),
+
+    /// Error reported by the execution runtime
+    #[error("runtime error: {0}")]
+    RuntimeError(String),
+
+    /// Error on an internal communication channel
+    #[error("channel error: {0}")]
+    ChannelError(String),
+
+    /// Requested execution target is outside of the retained history window
+    #[error("requested state target is older than the retained history window")]
+    StateTargetTooOld,
+}
@@ -0,0 +1,17 @@
+// Copyright (c) 2022 MASSA LABS <info@massa.net>
+
+//! This crate exports generic traits and types used to interact with the
+//! execution worker, without depending on its internal implementation.
+
+#![warn(missing_docs)]
+
+mod controller_traits;
+mod error;
+mod types;
+
+pub use controller_traits::{ExecutionController, ExecutionManager};
+pub use error::ExecutionError;
+pub use types::{
+    ExecutionOutput, ExecutionStackElement, ExecutionTrace, FeeHistory, ReadOnlyExecutionRequest,
+    ReadOnlyExecutionTarget, ReadOnlyStateTarget, SlotFeeStats, StateOverride, TraceFilter,
+};
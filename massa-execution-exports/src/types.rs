@@ -0,0 +1,171 @@
+// Copyright (c) 2022 MASSA LABS <info@massa.net>
+
+//! This module exports generic types shared between the execution worker
+//! and its consumers through the `ExecutionController` interface.
+
+use massa_models::output_event::SCOutputEvent;
+use massa_models::prehash::Map;
+use massa_models::Address;
+use massa_models::Amount;
+use massa_models::BlockId;
+use massa_models::Slot;
+use std::collections::BTreeMap;
+
+/// An element of the call stack of a read-only execution
+#[derive(Clone, Debug)]
+pub struct ExecutionStackElement {
+    /// called address
+    pub address: Address,
+    /// coins transferred along with the call
+    pub coins: Amount,
+    /// list of addresses owned by the bytecode being executed
+    pub owned_addresses: Vec<Address>,
+}
+
+/// What a read-only execution is targeting
+#[derive(Clone, Debug)]
+pub enum ReadOnlyExecutionTarget {
+    /// Execute a function call
+    FunctionCall {
+        /// called address
+        target_addr: Address,
+        /// called function name
+        target_func: String,
+        /// parameter passed to the function
+        parameter: Vec<u8>,
+    },
+    /// Execute raw bytecode
+    BytecodeExecution(Vec<u8>),
+}
+
+/// A state override applied to a single address before a read-only execution,
+/// on top of the speculative snapshot it is run against.
+///
+/// Any field left as `None` / empty is taken from the snapshot unchanged.
+/// Overrides never leak outside of the throwaway read-only context: they are
+/// never written back to the finalized or active ledger.
+#[derive(Clone, Debug, Default)]
+pub struct StateOverride {
+    /// override for the parallel (classic) balance of the address
+    pub parallel_balance: Option<Amount>,
+    /// override for the sequential (roll-involved) balance of the address
+    pub sequential_balance: Option<Amount>,
+    /// datastore entries to set or overwrite
+    pub datastore: BTreeMap<Vec<u8>, Vec<u8>>,
+    /// override for the bytecode stored at the address
+    pub bytecode: Option<Vec<u8>>,
+}
+
+/// The ledger point in time a read-only execution should run against.
+///
+/// When absent, the execution runs against the moving active (speculative) tip,
+/// which is non-deterministic across calls as the blockclique advances.
+#[derive(Clone, Debug)]
+pub enum ReadOnlyStateTarget {
+    /// Run against the ledger as finalized at this slot
+    FinalSlot(Slot),
+    /// Run against the ledger as finalized right after this block
+    BlockId(BlockId),
+}
+
+/// A request for a read-only execution
+#[derive(Clone, Debug)]
+pub struct ReadOnlyExecutionRequest {
+    /// Maximum gas that the call can use
+    pub max_gas: u64,
+    /// Call stack to simulate, the final caller being the last element
+    pub call_stack: Vec<ExecutionStackElement>,
+    /// What to execute
+    pub target: ReadOnlyExecutionTarget,
+    /// Per-address state overrides layered on top of the speculative snapshot
+    /// before execution, allowing callers to simulate hypothetical state
+    /// (e.g. "what would this call return if address X had balance B?").
+    pub state_overrides: Map<Address, StateOverride>,
+    /// The ledger point in time to execute against. Defaults to the active tip
+    /// when `None`, for reproducible historical reads use `Some(..)`.
+    pub state_target: Option<ReadOnlyStateTarget>,
+    /// When set, `ExecutionOutput::trace` is populated with the full call tree
+    /// of the execution, reconstructed from the inter-SC-call stack.
+    pub trace: bool,
+    /// Skip the read-only result cache and force a fresh execution, even if a
+    /// memoized output for an identical request and state revision exists.
+    pub bypass_cache: bool,
+}
+
+/// One node of the call tree produced when an execution is traced.
+///
+/// Mirrors the localized trace API of full nodes: it records everything
+/// `SCOutputEvent` alone cannot express, namely exactly how a chain of
+/// `call`/`local_call` invocations unfolded and where coins moved.
+#[derive(Clone, Debug)]
+pub struct ExecutionTrace {
+    /// address that initiated this call
+    pub caller_address: Address,
+    /// address that was called
+    pub callee_address: Address,
+    /// name of the function that was called
+    pub called_function: String,
+    /// raw parameters passed to the called function
+    pub parameters: Vec<u8>,
+    /// raw return value of the called function, if the call succeeded
+    pub return_value: Option<Vec<u8>>,
+    /// coins transferred to the callee as part of this call
+    pub coins: Amount,
+    /// gas consumed by this call, not counting its children
+    pub gas_cost: u64,
+    /// sub-calls triggered by this call, in the order they were made
+    pub sub_calls: Vec<ExecutionTrace>,
+}
+
+/// Filter used to query previously recorded call traces
+#[derive(Clone, Debug, Default)]
+pub struct TraceFilter {
+    /// only return traces at or after this slot
+    pub start: Option<Slot>,
+    /// only return traces at or before this slot
+    pub end: Option<Slot>,
+    /// only return traces whose top-level caller is this address
+    pub caller_address: Option<Address>,
+    /// only return traces whose top-level callee is this address
+    pub callee_address: Option<Address>,
+}
+
+/// Fee statistics for a single executed slot
+#[derive(Clone, Debug)]
+pub struct SlotFeeStats {
+    /// slot these statistics were computed for
+    pub slot: Slot,
+    /// number of operations executed in this slot's block
+    pub operation_count: u64,
+    /// lowest fee paid among the slot's operations
+    pub min_fee: Amount,
+    /// highest fee paid among the slot's operations
+    pub max_fee: Amount,
+    /// average fee paid across the slot's operations
+    pub average_fee: Amount,
+    /// fee at each of the requested percentiles, in the same order they were requested
+    pub fee_percentiles: Vec<Amount>,
+}
+
+/// Aggregated operation fee statistics over a range of executed slots
+#[derive(Clone, Debug, Default)]
+pub struct FeeHistory {
+    /// percentiles that were requested, in the order `fee_percentiles` follows in each entry
+    pub percentiles: Vec<f64>,
+    /// per-slot statistics, ordered by increasing slot
+    pub slots: BTreeMap<Slot, SlotFeeStats>,
+}
+
+/// Output of an execution
+#[derive(Clone, Debug, Default)]
+pub struct ExecutionOutput {
+    /// slot at which the output was produced
+    pub slot: Slot,
+    /// events emitted during the execution
+    pub events: Vec<SCOutputEvent>,
+    /// gas consumed by the execution
+    pub gas_cost: u64,
+    /// call tree of the execution, only populated when the originating
+    /// request had `trace` set
+    pub trace: Option<ExecutionTrace>,
+}